@@ -1,90 +1,454 @@
 use alloc::sync::Arc;
 use core::{fmt, ops::Deref, str::FromStr};
-use std::thread;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{application::fatal_error, Runnable};
+use crossbeam_channel::Select;
 use itertools::Itertools;
+use rand::Rng;
+use serde::Serialize;
 use tokio::runtime::Runtime as TokioRuntime;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use ibc::{core::ics24_host::identifier::ChainId, events::IbcEvent};
+use ibc::{
+    core::{ics04_channel::packet::Packet, ics24_host::identifier::ChainId},
+    events::IbcEvent,
+    Height,
+};
 
 use ibc_relayer::{
     config::ChainConfig,
-    event::monitor::{EventMonitor, EventReceiver},
+    event::monitor::{EventMonitor, EventReceiver, MonitorCmd, TxMonitorCmd},
 };
 
 use crate::prelude::*;
 
+/// Polling interval used while waiting for either a new event batch or a
+/// shutdown request. Keeping this short bounds how long `listen` takes to
+/// notice that a signal has arrived.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Delay before the first websocket resubscription attempt after the
+/// connection to the chain is lost.
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound the exponential backoff is capped at between resubscription
+/// attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// Give up and return a fatal error after this many consecutive failed
+/// resubscription attempts.
+const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+
+/// The kind of IBC event a [`EventFilter`] selects for.
+///
+/// `NewBlock` and `Tx` keep their historical, coarse-grained meaning (any
+/// new block, or any transaction event that isn't a chain error); the rest
+/// select a single concrete `IbcEvent` variant so that a filter can target,
+/// say, just `SendPacket` events.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum EventFilter {
+pub enum EventKind {
     NewBlock,
     Tx,
+    CreateClient,
+    UpdateClient,
+    OpenInitConnection,
+    SendPacket,
+    WriteAcknowledgement,
+    Timeout,
 }
 
-impl EventFilter {
-    pub fn matches(&self, event: &IbcEvent) -> bool {
-        match self {
-            EventFilter::NewBlock => matches!(event, IbcEvent::NewBlock(_)),
-            EventFilter::Tx => !(matches!(event, IbcEvent::NewBlock(_) | IbcEvent::ChainError(_))),
-        }
+impl EventKind {
+    /// Whether events of this kind carry a packet, and so can be narrowed
+    /// down with attribute constraints such as `src_channel=channel-0`.
+    fn supports_attributes(&self) -> bool {
+        matches!(
+            self,
+            Self::SendPacket | Self::WriteAcknowledgement | Self::Timeout
+        )
     }
 }
 
-impl fmt::Display for EventFilter {
+impl fmt::Display for EventKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::NewBlock => write!(f, "NewBlock"),
             Self::Tx => write!(f, "Tx"),
+            Self::CreateClient => write!(f, "CreateClient"),
+            Self::UpdateClient => write!(f, "UpdateClient"),
+            Self::OpenInitConnection => write!(f, "OpenInitConnection"),
+            Self::SendPacket => write!(f, "SendPacket"),
+            Self::WriteAcknowledgement => write!(f, "WriteAcknowledgement"),
+            Self::Timeout => write!(f, "Timeout"),
         }
     }
 }
 
-impl FromStr for EventFilter {
+impl FromStr for EventKind {
     type Err = Box<dyn std::error::Error + Send + Sync + 'static>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "NewBlock" => Ok(Self::NewBlock),
             "Tx" => Ok(Self::Tx),
+            "CreateClient" => Ok(Self::CreateClient),
+            "UpdateClient" => Ok(Self::UpdateClient),
+            "OpenInitConnection" => Ok(Self::OpenInitConnection),
+            "SendPacket" => Ok(Self::SendPacket),
+            "WriteAcknowledgement" => Ok(Self::WriteAcknowledgement),
+            "Timeout" => Ok(Self::Timeout),
             invalid => Err(format!("unrecognized event type: {}", invalid).into()),
         }
     }
 }
 
+/// A single `key=value` constraint on the attributes of a matched event,
+/// e.g. `src_channel=channel-0` inside `SendPacket[src_channel=channel-0]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct AttributeMatch {
+    key: String,
+    value: String,
+}
+
+impl fmt::Display for AttributeMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+/// Selects which events `listen` reports: an [`EventKind`], optionally
+/// narrowed down by a list of attribute constraints such as
+/// `SendPacket[src_channel=channel-0,src_port=transfer]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventFilter {
+    kind: EventKind,
+    attributes: Vec<AttributeMatch>,
+}
+
+impl EventFilter {
+    fn new(kind: EventKind) -> Self {
+        Self {
+            kind,
+            attributes: Vec::new(),
+        }
+    }
+
+    pub fn matches(&self, event: &IbcEvent) -> bool {
+        self.kind_matches(event) && self.attributes.iter().all(|a| self.attribute_matches(event, a))
+    }
+
+    fn kind_matches(&self, event: &IbcEvent) -> bool {
+        match self.kind {
+            EventKind::NewBlock => matches!(event, IbcEvent::NewBlock(_)),
+            EventKind::Tx => !(matches!(event, IbcEvent::NewBlock(_) | IbcEvent::ChainError(_))),
+            EventKind::CreateClient => matches!(event, IbcEvent::CreateClient(_)),
+            EventKind::UpdateClient => matches!(event, IbcEvent::UpdateClient(_)),
+            EventKind::OpenInitConnection => matches!(event, IbcEvent::OpenInitConnection(_)),
+            EventKind::SendPacket => matches!(event, IbcEvent::SendPacket(_)),
+            EventKind::WriteAcknowledgement => matches!(event, IbcEvent::WriteAcknowledgement(_)),
+            EventKind::Timeout => {
+                matches!(
+                    event,
+                    IbcEvent::TimeoutPacket(_) | IbcEvent::TimeoutOnClosePacket(_)
+                )
+            }
+        }
+    }
+
+    fn attribute_matches(&self, event: &IbcEvent, attr: &AttributeMatch) -> bool {
+        packet_attribute(event, &attr.key)
+            .map(|actual| actual == attr.value)
+            .unwrap_or(false)
+    }
+}
+
+/// The attribute keys understood by [`packet_attribute`], used to reject
+/// unsupported or misspelled attribute names in `EventFilter::from_str`
+/// instead of letting them silently match nothing.
+const PACKET_ATTRIBUTE_KEYS: &[&str] =
+    &["src_channel", "src_port", "dst_channel", "dst_port", "sequence"];
+
+/// Extracts the packet carried by the given event, if any. Only
+/// packet-flow events (`SendPacket`, `ReceivePacket`,
+/// `WriteAcknowledgement`, `AcknowledgePacket`, `TimeoutPacket`,
+/// `TimeoutOnClosePacket`) carry one.
+fn event_packet(event: &IbcEvent) -> Option<&Packet> {
+    match event {
+        IbcEvent::SendPacket(e) => Some(&e.packet),
+        IbcEvent::ReceivePacket(e) => Some(&e.packet),
+        IbcEvent::WriteAcknowledgement(e) => Some(&e.packet),
+        IbcEvent::AcknowledgePacket(e) => Some(&e.packet),
+        IbcEvent::TimeoutPacket(e) => Some(&e.packet),
+        IbcEvent::TimeoutOnClosePacket(e) => Some(&e.packet),
+        _ => None,
+    }
+}
+
+/// Extracts a named attribute (one of [`PACKET_ATTRIBUTE_KEYS`]) from the
+/// packet carried by the given event, if any.
+fn packet_attribute(event: &IbcEvent, key: &str) -> Option<String> {
+    let packet = event_packet(event)?;
+
+    match key {
+        "src_channel" => Some(packet.source_channel.to_string()),
+        "src_port" => Some(packet.source_port.to_string()),
+        "dst_channel" => Some(packet.destination_channel.to_string()),
+        "dst_port" => Some(packet.destination_port.to_string()),
+        "sequence" => Some(packet.sequence.to_string()),
+        _ => None,
+    }
+}
+
+impl fmt::Display for EventFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+
+        if !self.attributes.is_empty() {
+            write!(f, "[{}]", self.attributes.iter().format(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for EventFilter {
+    type Err = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind_str, attrs_str) = match s.find('[') {
+            Some(start) => {
+                let end = s
+                    .rfind(']')
+                    .ok_or_else(|| format!("unterminated attribute list in '{}'", s))?;
+
+                (&s[..start], Some(&s[start + 1..end]))
+            }
+            None => (s, None),
+        };
+
+        let kind = kind_str.parse()?;
+
+        let attributes = match attrs_str {
+            Some(attrs_str) if !attrs_str.is_empty() => attrs_str
+                .split(',')
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').ok_or_else(|| {
+                        format!("expected 'key=value' attribute constraint, got '{}'", pair)
+                    })?;
+
+                    Ok(AttributeMatch {
+                        key: key.trim().to_owned(),
+                        value: value.trim().to_owned(),
+                    })
+                })
+                .collect::<Result<Vec<_>, Self::Err>>()?,
+            _ => Vec::new(),
+        };
+
+        if !attributes.is_empty() && !kind.supports_attributes() {
+            return Err(format!(
+                "event kind '{}' does not carry a packet, so it cannot be narrowed down with attribute constraints",
+                kind
+            )
+            .into());
+        }
+
+        for attr in &attributes {
+            if !PACKET_ATTRIBUTE_KEYS.contains(&attr.key.as_str()) {
+                return Err(format!(
+                    "unrecognized attribute key '{}', expected one of: {}",
+                    attr.key,
+                    PACKET_ATTRIBUTE_KEYS.iter().format(", ")
+                )
+                .into());
+            }
+        }
+
+        Ok(Self { kind, attributes })
+    }
+}
+
+/// The output format used to report events on stdout.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    /// Human-readable, multi-line output via the tracing logger (default).
+    Pretty,
+    /// One JSON object per line, written directly to stdout so that it can
+    /// be piped into tools such as `jq` or an indexer.
+    Json,
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::Pretty
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pretty => write!(f, "pretty"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for Output {
+    type Err = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            invalid => Err(format!("unrecognized output format: {}", invalid).into()),
+        }
+    }
+}
+
+/// A newline-delimited JSON envelope emitted once per event batch that has
+/// at least one matching event, ahead of those events, so that consumers can
+/// associate each event with the chain and height it was observed at.
+/// Batches with no matching events produce no output at all.
+///
+/// `ChainId` and `Height` don't implement `serde::Serialize` upstream, so
+/// this wrapper renders them via their existing `Display` impls instead of
+/// deriving through them directly.
+#[derive(Serialize)]
+struct JsonBatchHeader {
+    chain_id: String,
+    height: String,
+}
+
+impl JsonBatchHeader {
+    fn new(chain_id: &ChainId, height: Height) -> Self {
+        Self {
+            chain_id: chain_id.to_string(),
+            height: height.to_string(),
+        }
+    }
+}
+
+/// A newline-delimited JSON wrapper around a single [`IbcEvent`]. `IbcEvent`
+/// doesn't implement `serde::Serialize` upstream, so this surfaces the
+/// matched variant's name plus, for packet-flow events, the same structured
+/// packet attributes `--events` filters on (`src_channel`, `src_port`,
+/// `dst_channel`, `dst_port`, `sequence`) rather than an opaque `Debug`
+/// string, so that `jq` and indexers can query them directly.
+#[derive(Serialize)]
+struct JsonEvent {
+    kind: String,
+    packet: Option<JsonPacket>,
+}
+
+impl JsonEvent {
+    fn new(event: &IbcEvent) -> Self {
+        Self {
+            kind: event_kind_name(event),
+            packet: event_packet(event).map(JsonPacket::new),
+        }
+    }
+}
+
+/// The packet attributes of a packet-flow event, rendered as structured
+/// JSON fields instead of via `Packet`'s `Display`/`Debug` impls.
+#[derive(Serialize)]
+struct JsonPacket {
+    src_channel: String,
+    src_port: String,
+    dst_channel: String,
+    dst_port: String,
+    sequence: String,
+}
+
+impl JsonPacket {
+    fn new(packet: &Packet) -> Self {
+        Self {
+            src_channel: packet.source_channel.to_string(),
+            src_port: packet.source_port.to_string(),
+            dst_channel: packet.destination_channel.to_string(),
+            dst_port: packet.destination_port.to_string(),
+            sequence: packet.sequence.to_string(),
+        }
+    }
+}
+
+/// Extracts the variant name out of an `IbcEvent`'s `Debug` rendering, e.g.
+/// `"SendPacket"` out of `SendPacket(SendPacket { .. })`.
+fn event_kind_name(event: &IbcEvent) -> String {
+    let debug = format!("{:?}", event);
+    debug
+        .split(|c: char| c == '(' || c == ' ' || c == '{')
+        .next()
+        .unwrap_or(&debug)
+        .to_owned()
+}
+
 #[derive(Debug, Parser, PartialEq)]
 pub struct ListenCmd {
-    /// Identifier of the chain to listen for events from
+    /// Identifier of the chain to listen for events from. Can be repeated
+    /// to listen to several chains at once, e.g. `--chain a --chain b`.
     #[clap(
         long = "chain",
-        required = true,
         help_heading = "REQUIRED",
-        value_name = "CHAIN_ID"
+        value_name = "CHAIN_ID",
+        required_unless_present = "all_chains",
+        conflicts_with = "all_chains",
+        multiple_occurrences = true
     )]
-    chain_id: ChainId,
-
-    /// Add an event type to listen for, can be repeated.
-    /// Listen for all events by default (available: Tx, NewBlock).
+    chain_ids: Vec<ChainId>,
+
+    /// Listen for events on every chain in the configuration, instead of
+    /// the ones given with `--chain`.
+    #[clap(long = "all-chains", conflicts_with = "chain_ids")]
+    all_chains: bool,
+
+    /// Add an event type to listen for, can be repeated. Listen for all
+    /// events by default (available: Tx, NewBlock, CreateClient,
+    /// UpdateClient, OpenInitConnection, SendPacket, WriteAcknowledgement,
+    /// Timeout). Only the packet events (SendPacket, WriteAcknowledgement,
+    /// Timeout) accept attribute constraints, e.g.
+    /// `SendPacket[src_channel=channel-0,src_port=transfer]`.
     #[clap(long = "events", value_name = "EVENT", multiple_values = true)]
     events: Vec<EventFilter>,
+
+    /// Select the output format: `pretty` for human-readable logs (default),
+    /// or `json` for newline-delimited JSON suitable for piping into `jq`
+    /// or an indexer.
+    #[clap(long = "output", value_name = "OUTPUT", default_value_t)]
+    output: Output,
 }
 
 impl ListenCmd {
     fn cmd(&self) -> Result<(), Box<dyn std::error::Error>> {
         let config = app_config();
 
-        let chain_config = config
-            .find_chain(&self.chain_id)
-            .ok_or_else(|| format!("chain '{}' not found in configuration", self.chain_id))?;
+        let chain_configs = if self.all_chains {
+            config.chains.clone()
+        } else {
+            self.chain_ids
+                .iter()
+                .map(|chain_id| {
+                    config
+                        .find_chain(chain_id)
+                        .cloned()
+                        .ok_or_else(|| format!("chain '{}' not found in configuration", chain_id))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
 
+        let default_events = [EventFilter::new(EventKind::Tx), EventFilter::new(EventKind::NewBlock)];
         let events = if self.events.is_empty() {
-            &[EventFilter::Tx, EventFilter::NewBlock]
+            &default_events
         } else {
             self.events.as_slice()
         };
 
-        listen(chain_config, events)
+        listen(&chain_configs, events, self.output)
     }
 }
 
@@ -95,25 +459,161 @@ impl Runnable for ListenCmd {
     }
 }
 
-/// Listen to events
+/// The monitor and channels used to listen to a single chain, along with
+/// enough state to reconnect it independently of its siblings.
+struct ChainListener {
+    config: ChainConfig,
+    rx: EventReceiver,
+    tx_cmd: TxMonitorCmd,
+    reconnect_attempts: u32,
+    /// Set while this listener is down, to the instant its next
+    /// resubscription attempt is due. A listener with `retry_at` set is
+    /// left out of the merge loop's `Select` so that backing off one chain
+    /// can't stall event delivery for the others.
+    retry_at: Option<Instant>,
+}
+
+impl ChainListener {
+    fn spawn(config: ChainConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let (rx, tx_cmd) = spawn_monitor(&config)?;
+
+        Ok(Self {
+            config,
+            rx,
+            tx_cmd,
+            reconnect_attempts: 0,
+            retry_at: None,
+        })
+    }
+
+    /// Marks this listener as down and schedules its next resubscription
+    /// attempt, without blocking: the caller keeps polling the other
+    /// listeners and calls [`ChainListener::resume_if_due`], which only
+    /// resubscribes this one once `retry_at` elapses.
+    fn schedule_reconnect(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.reconnect_attempts += 1;
+
+        if self.reconnect_attempts > MAX_RECONNECT_ATTEMPTS {
+            return Err(format!(
+                "[{}] giving up after {} failed reconnection attempts",
+                self.config.id, MAX_RECONNECT_ATTEMPTS
+            )
+            .into());
+        }
+
+        let delay = reconnect_delay(self.reconnect_attempts);
+        warn!(
+            "[{}] event monitor connection lost, reconnecting in {:?} (attempt {}/{})",
+            self.config.id, delay, self.reconnect_attempts, MAX_RECONNECT_ATTEMPTS
+        );
+        self.retry_at = Some(Instant::now() + delay);
+
+        Ok(())
+    }
+
+    /// If this listener is down and its backoff delay has elapsed, attempts
+    /// to resubscribe. Does nothing for a listener that's currently live or
+    /// still waiting out its backoff.
+    fn resume_if_due(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        match self.retry_at {
+            Some(retry_at) if Instant::now() >= retry_at => (),
+            _ => return Ok(()),
+        }
+
+        match spawn_monitor(&self.config) {
+            Ok((rx, tx_cmd)) => {
+                self.rx = rx;
+                self.tx_cmd = tx_cmd;
+                self.retry_at = None;
+                info!("[{}] resubscribed after connection loss", self.config.id);
+                Ok(())
+            }
+            Err(e) => {
+                error!("[{}] could not resubscribe: {}", self.config.id, e);
+                self.schedule_reconnect()
+            }
+        }
+    }
+}
+
+/// Listen to events coming from one or more chains, merging them into a
+/// single output stream. This mirrors the `start-multi` design: one
+/// [`EventMonitor`] is spawned per chain, and their receivers are merged
+/// with a dynamically-sized [`Select`].
 pub fn listen(
-    config: &ChainConfig,
+    configs: &[ChainConfig],
     filters: &[EventFilter],
+    output: Output,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let rt = Arc::new(TokioRuntime::new()?);
-    let (event_monitor, rx) = subscribe(config, rt)?;
+    let mut listeners = configs
+        .iter()
+        .cloned()
+        .map(ChainListener::spawn)
+        .collect::<Result<Vec<_>, _>>()?;
 
-    info!(
-        "[{}] listening for queries {}",
-        config.id,
-        event_monitor.queries().iter().format(", "),
-    );
+    let shutdown = install_shutdown_handler()?;
 
-    thread::spawn(|| event_monitor.run());
+    loop {
+        if shutdown.load(Ordering::Relaxed) {
+            info!("shutdown requested, unsubscribing from all chains");
+
+            for listener in &listeners {
+                let _ = listener.tx_cmd.send(MonitorCmd::Shutdown);
+            }
+
+            break;
+        }
+
+        for listener in &mut listeners {
+            listener.resume_if_due()?;
+        }
+
+        // Chains currently backing off are left out of the `Select` below,
+        // so a down chain is merely skipped rather than stalling delivery
+        // for the others while its backoff elapses.
+        let live_indices = listeners
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.retry_at.is_none())
+            .map(|(i, _)| i)
+            .collect_vec();
+
+        if live_indices.is_empty() {
+            thread::sleep(SHUTDOWN_POLL_INTERVAL);
+            continue;
+        }
+
+        let mut select = Select::new();
+        for &i in &live_indices {
+            select.recv(&listeners[i].rx);
+        }
+
+        let oper = match select.select_timeout(SHUTDOWN_POLL_INTERVAL) {
+            Ok(oper) => oper,
+            // Timed out without any chain producing an event; loop back
+            // around to re-check the shutdown flag.
+            Err(_) => continue,
+        };
+
+        let index = live_indices[oper.index()];
+        let event_batch = oper.recv(&listeners[index].rx);
+
+        let event_batch = match event_batch {
+            Ok(event_batch) => event_batch,
+            // The monitor has terminated and dropped the sending end of the
+            // channel, so no further batches can arrive from it: resubscribe.
+            Err(_) => {
+                listeners[index].schedule_reconnect()?;
+                continue;
+            }
+        };
+
+        let chain_id = &listeners[index].config.id;
 
-    while let Ok(event_batch) = rx.recv() {
         match event_batch {
             Ok(batch) => {
+                listeners[index].reconnect_attempts = 0;
+
                 let matching_events = batch
                     .events
                     .into_iter()
@@ -124,21 +624,110 @@ pub fn listen(
                     continue;
                 }
 
-                info!("- event batch at height {}", batch.height);
+                match output {
+                    Output::Pretty => {
+                        info!("[{}] event batch at height {}", chain_id, batch.height);
 
-                for event in matching_events {
-                    info!("+ {:#?}", event);
-                }
+                        for event in &matching_events {
+                            info!("+ {:#?}", event);
+                        }
 
-                info!("");
+                        info!("");
+                    }
+                    Output::Json => {
+                        print_json_line(&JsonBatchHeader::new(chain_id, batch.height));
+
+                        for event in &matching_events {
+                            print_json_line(&JsonEvent::new(event));
+                        }
+                    }
+                }
+            }
+            // `EventMonitor::run` retries the websocket connection
+            // internally and only drops the channel on `Shutdown`, so a
+            // terminal connection error (e.g. the node restarting) surfaces
+            // here as an `Err` inside the batch rather than a closed
+            // channel. Treat it the same way: resubscribe with backoff
+            // instead of just logging and waiting for a batch that will
+            // never come.
+            Err(e) => {
+                error!("[{}] - error: {}", chain_id, e);
+                listeners[index].schedule_reconnect()?;
             }
-            Err(e) => error!("- error: {}", e),
         }
     }
 
     Ok(())
 }
 
+/// Computes the delay before the `attempt`-th resubscription attempt: an
+/// exponential backoff starting at [`INITIAL_RECONNECT_DELAY`], doubling on
+/// each attempt, capped at [`MAX_RECONNECT_DELAY`], with a bit of random
+/// jitter added so that many relayers reconnecting to the same node don't
+/// all retry in lockstep.
+fn reconnect_delay(attempt: u32) -> Duration {
+    let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+    let backoff = INITIAL_RECONNECT_DELAY
+        .checked_mul(factor)
+        .unwrap_or(MAX_RECONNECT_DELAY)
+        .min(MAX_RECONNECT_DELAY);
+
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=100));
+
+    backoff + jitter
+}
+
+/// Subscribes to `config` and spawns the resulting [`EventMonitor`] on its
+/// own thread, returning the receiver and command sender used to interact
+/// with it.
+fn spawn_monitor(
+    config: &ChainConfig,
+) -> Result<(EventReceiver, TxMonitorCmd), Box<dyn std::error::Error>> {
+    let rt = Arc::new(TokioRuntime::new()?);
+    let (event_monitor, rx, tx_cmd) = subscribe(config, rt)?;
+
+    info!(
+        "[{}] listening for queries {}",
+        config.id,
+        event_monitor.queries().iter().format(", "),
+    );
+
+    thread::spawn(|| event_monitor.run());
+
+    Ok((rx, tx_cmd))
+}
+
+/// Installs a SIGINT/SIGTERM (and Ctrl-C on every platform) handler that
+/// flips a shared flag once a termination signal is received, so that the
+/// receive loop in [`listen`] can notice it and shut down cleanly instead of
+/// being killed outright.
+fn install_shutdown_handler() -> Result<Arc<AtomicBool>, Box<dyn std::error::Error>> {
+    let shutdown = Arc::new(AtomicBool::new(false));
+
+    let ctrlc_flag = shutdown.clone();
+    ctrlc::set_handler(move || ctrlc_flag.store(true, Ordering::Relaxed))
+        .map_err(|e| format!("could not install Ctrl-C handler: {}", e))?;
+
+    #[cfg(unix)]
+    {
+        use signal_hook::{consts::SIGTERM, flag};
+        flag::register(SIGTERM, shutdown.clone())
+            .map_err(|e| format!("could not install SIGTERM handler: {}", e))?;
+    }
+
+    Ok(shutdown)
+}
+
+/// Serializes `value` to a single line of JSON and writes it to stdout,
+/// bypassing the tracing logger so that `--output json` produces a clean
+/// newline-delimited stream consumers can pipe into `jq` or an indexer.
+fn print_json_line(value: &impl Serialize) {
+    match serde_json::to_string(value) {
+        Ok(line) => println!("{}", line),
+        Err(e) => error!("- could not serialize event to JSON: {}", e),
+    }
+}
+
 fn event_match(event: &IbcEvent, filters: &[EventFilter]) -> bool {
     filters.iter().any(|f| f.matches(event))
 }
@@ -146,8 +735,8 @@ fn event_match(event: &IbcEvent, filters: &[EventFilter]) -> bool {
 fn subscribe(
     chain_config: &ChainConfig,
     rt: Arc<TokioRuntime>,
-) -> Result<(EventMonitor, EventReceiver), Box<dyn std::error::Error>> {
-    let (mut event_monitor, rx, _) = EventMonitor::new(
+) -> Result<(EventMonitor, EventReceiver, TxMonitorCmd), Box<dyn std::error::Error>> {
+    let (mut event_monitor, rx, tx_cmd) = EventMonitor::new(
         chain_config.id.clone(),
         chain_config.websocket_addr.clone(),
         rt,
@@ -158,12 +747,12 @@ fn subscribe(
         .subscribe()
         .map_err(|e| format!("could not initialize subscriptions: {}", e))?;
 
-    Ok((event_monitor, rx))
+    Ok((event_monitor, rx, tx_cmd))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{EventFilter, ListenCmd};
+    use super::{EventFilter, ListenCmd, Output};
 
     use std::str::FromStr;
 
@@ -174,8 +763,10 @@ mod tests {
     fn test_listen_required_only() {
         assert_eq!(
             ListenCmd {
-                chain_id: ChainId::from_string("chain_id"),
-                events: vec!()
+                chain_ids: vec!(ChainId::from_string("chain_id")),
+                all_chains: false,
+                events: vec!(),
+                output: Output::Pretty
             },
             ListenCmd::parse_from(&["test", "--chain", "chain_id"])
         )
@@ -185,8 +776,10 @@ mod tests {
     fn test_listen_single_event() {
         assert_eq!(
             ListenCmd {
-                chain_id: ChainId::from_string("chain_id"),
-                events: vec!(EventFilter::from_str("Tx").unwrap())
+                chain_ids: vec!(ChainId::from_string("chain_id")),
+                all_chains: false,
+                events: vec!(EventFilter::from_str("Tx").unwrap()),
+                output: Output::Pretty
             },
             ListenCmd::parse_from(&["test", "--chain", "chain_id", "--events", "Tx"])
         )
@@ -196,11 +789,13 @@ mod tests {
     fn test_listen_multiple_events() {
         assert_eq!(
             ListenCmd {
-                chain_id: ChainId::from_string("chain_id"),
+                chain_ids: vec!(ChainId::from_string("chain_id")),
+                all_chains: false,
                 events: vec!(
                     EventFilter::from_str("Tx").unwrap(),
                     EventFilter::from_str("NewBlock").unwrap()
-                )
+                ),
+                output: Output::Pretty
             },
             ListenCmd::parse_from(&[
                 "test", "--chain", "chain_id", "--events", "Tx", "--events", "NewBlock"
@@ -212,16 +807,79 @@ mod tests {
     fn test_listen_multiple_events_single_flag() {
         assert_eq!(
             ListenCmd {
-                chain_id: ChainId::from_string("chain_id"),
+                chain_ids: vec!(ChainId::from_string("chain_id")),
+                all_chains: false,
                 events: vec!(
                     EventFilter::from_str("Tx").unwrap(),
                     EventFilter::from_str("NewBlock").unwrap()
-                )
+                ),
+                output: Output::Pretty
             },
             ListenCmd::parse_from(&["test", "--chain", "chain_id", "--events", "Tx", "NewBlock"])
         )
     }
 
+    #[test]
+    fn test_listen_output_json() {
+        assert_eq!(
+            ListenCmd {
+                chain_ids: vec!(ChainId::from_string("chain_id")),
+                all_chains: false,
+                events: vec!(),
+                output: Output::Json
+            },
+            ListenCmd::parse_from(&["test", "--chain", "chain_id", "--output", "json"])
+        )
+    }
+
+    #[test]
+    fn test_listen_unknown_output() {
+        assert!(ListenCmd::try_parse_from(&[
+            "test", "--chain", "chain_id", "--output", "xml"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_listen_packet_event_with_attributes() {
+        assert_eq!(
+            ListenCmd {
+                chain_ids: vec!(ChainId::from_string("chain_id")),
+                all_chains: false,
+                events: vec!(
+                    EventFilter::from_str("SendPacket[src_channel=channel-0,src_port=transfer]")
+                        .unwrap()
+                ),
+                output: Output::Pretty
+            },
+            ListenCmd::parse_from(&[
+                "test",
+                "--chain",
+                "chain_id",
+                "--events",
+                "SendPacket[src_channel=channel-0,src_port=transfer]"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_listen_event_filter_malformed_attributes() {
+        assert!(EventFilter::from_str("SendPacket[src_channel]").is_err());
+        assert!(EventFilter::from_str("SendPacket[src_channel=channel-0").is_err());
+    }
+
+    #[test]
+    fn test_listen_event_filter_attributes_on_non_packet_kind() {
+        assert!(EventFilter::from_str("CreateClient[client_id=foo]").is_err());
+        assert!(EventFilter::from_str("NewBlock[height=1]").is_err());
+    }
+
+    #[test]
+    fn test_listen_event_filter_unknown_attribute_key() {
+        assert!(EventFilter::from_str("SendPacket[srcchannel=channel-0]").is_err());
+        assert!(EventFilter::from_str("SendPacket[src_channel=channel-0,bogus=1]").is_err());
+    }
+
     #[test]
     fn test_listen_unknown_event_filter() {
         assert!(ListenCmd::try_parse_from(&[
@@ -238,4 +896,43 @@ mod tests {
     fn test_listen_unknown_no_chain() {
         assert!(ListenCmd::try_parse_from(&["test"]).is_err())
     }
+
+    #[test]
+    fn test_listen_multiple_chains() {
+        assert_eq!(
+            ListenCmd {
+                chain_ids: vec!(
+                    ChainId::from_string("chain_a"),
+                    ChainId::from_string("chain_b")
+                ),
+                all_chains: false,
+                events: vec!(),
+                output: Output::Pretty
+            },
+            ListenCmd::parse_from(&[
+                "test", "--chain", "chain_a", "--chain", "chain_b"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_listen_all_chains() {
+        assert_eq!(
+            ListenCmd {
+                chain_ids: vec!(),
+                all_chains: true,
+                events: vec!(),
+                output: Output::Pretty
+            },
+            ListenCmd::parse_from(&["test", "--all-chains"])
+        )
+    }
+
+    #[test]
+    fn test_listen_all_chains_conflicts_with_chain() {
+        assert!(ListenCmd::try_parse_from(&[
+            "test", "--chain", "chain_id", "--all-chains"
+        ])
+        .is_err())
+    }
 }